@@ -0,0 +1,7 @@
+//! API servers exposed by the zkSync node.
+
+pub mod rest;
+
+// The `v1` REST API is the one most consumers embed against; re-export it here so callers
+// (and its own test suite) can reach it as `api_server::v1` without spelling out `rest`.
+pub use rest::v1;
@@ -0,0 +1,3 @@
+//! REST API implementations, one module per versioned scope.
+
+pub mod v1;
@@ -0,0 +1,303 @@
+//! Account information endpoints, scoped under `/api/v1/accounts`.
+
+// Built-in uses
+use std::collections::HashMap;
+
+// External uses
+use actix_web::{
+    web::{self, Json},
+    Scope,
+};
+
+// Workspace uses
+use zksync_config::ConfigurationOptions;
+use zksync_types::{AccountId, Address};
+
+// Local uses
+use crate::{
+    api_server::rest::v1::{client::AccountQuery, current_block, error::ApiError, JsonResult},
+    core_api_client::CoreApiClient,
+    utils::token_db_cache::TokenDBCache,
+};
+
+pub mod types;
+#[cfg(test)]
+mod tests;
+
+use self::types::{
+    AccountInfo, AccountOpReceipt, AccountReceipts, AccountTxReceipt, DepositingAccountBalances,
+    DepositingFunds,
+};
+
+fn parse_account_query(raw: &str) -> Result<AccountQuery, ApiError> {
+    if let Ok(id) = raw.parse::<u32>() {
+        return Ok(AccountQuery::Id(AccountId(id)));
+    }
+    raw.parse::<Address>()
+        .map(AccountQuery::Address)
+        .map_err(|_| ApiError::bad_request("account must be an id or an address"))
+}
+
+#[derive(Clone)]
+struct ApiAccountData {
+    tokens: TokenDBCache,
+    core_api_client: CoreApiClient,
+}
+
+impl ApiAccountData {
+    fn new(tokens: TokenDBCache, core_api_client: CoreApiClient) -> Self {
+        Self {
+            tokens,
+            core_api_client,
+        }
+    }
+
+    async fn account_info(&self, query: AccountQuery) -> Result<Option<AccountInfo>, ApiError> {
+        let mut storage = self
+            .tokens
+            .pool()
+            .access_storage()
+            .await
+            .map_err(ApiError::internal)?;
+
+        let account_state = match query {
+            AccountQuery::Id(id) => {
+                storage
+                    .chain()
+                    .account_schema()
+                    .account_state_by_id(id)
+                    .await
+                    .map_err(ApiError::internal)?
+            }
+            AccountQuery::Address(address) => {
+                storage
+                    .chain()
+                    .account_schema()
+                    .account_state_by_address(address)
+                    .await
+                    .map_err(ApiError::internal)?
+            }
+        };
+
+        let (id, address) = match account_state {
+            Some(state) => (Some(state.account_id), state.account.address),
+            None => return Ok(None),
+        };
+
+        let unconfirmed_deposits = self
+            .core_api_client
+            .get_unconfirmed_deposits(address)
+            .await
+            .map_err(ApiError::internal)?;
+
+        let mut balances = HashMap::new();
+        for (_eth_block, deposit) in
+            serde_json::from_value::<Vec<(u64, serde_json::Value)>>(unconfirmed_deposits)
+                .map_err(|err| ApiError::internal(err.to_string()))?
+        {
+            let token_id = deposit["data"]["token"]
+                .as_u64()
+                .ok_or_else(|| ApiError::internal("malformed unconfirmed deposit"))?;
+            let token = self
+                .tokens
+                .get_token(zksync_types::TokenId(token_id as u16))
+                .await
+                .map_err(ApiError::internal)?;
+            let symbol = token.map(|t| t.symbol).unwrap_or_default();
+
+            let amount = serde_json::from_value(deposit["data"]["amount"].clone())
+                .map_err(|err| ApiError::internal(err.to_string()))?;
+            let expected_accept_block = deposit["eth_block"].as_u64().unwrap_or_default() as u32;
+
+            balances.insert(
+                symbol,
+                DepositingFunds {
+                    amount,
+                    expected_accept_block,
+                },
+            );
+        }
+
+        Ok(Some(AccountInfo {
+            address,
+            id,
+            depositing: DepositingAccountBalances { balances },
+        }))
+    }
+}
+
+async fn account_info(
+    data: web::Data<ApiAccountData>,
+    account: web::Path<String>,
+) -> JsonResult<Option<AccountInfo>> {
+    let query = parse_account_query(&account)?;
+    Ok(Json(data.account_info(query).await?))
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct AccountReceiptsQuery {
+    block: Option<u32>,
+    index: Option<u32>,
+    direction: Option<String>,
+    limit: u32,
+}
+
+impl From<AccountReceiptsQuery> for AccountReceipts {
+    fn from(q: AccountReceiptsQuery) -> Self {
+        match (q.block, q.direction.as_deref()) {
+            (Some(block), Some("older")) => Self::older_than(block, q.index),
+            (Some(block), _) => Self::newer_than(block, q.index),
+            _ => Self::Latest,
+        }
+    }
+}
+
+async fn account_tx_receipts(
+    data: web::Data<ApiAccountData>,
+    account: web::Path<String>,
+    range: web::Query<AccountReceiptsQuery>,
+) -> JsonResult<Vec<AccountTxReceipt>> {
+    let query = parse_account_query(&account)?;
+    let range = range.into_inner();
+    let limit = range.limit;
+    let range = AccountReceipts::from(range);
+
+    let mut storage = data
+        .tokens
+        .pool()
+        .access_storage()
+        .await
+        .map_err(ApiError::internal)?;
+
+    let address = resolve_address(&mut storage, query).await?;
+    let responses = storage
+        .chain()
+        .operations_ext_schema()
+        .get_account_transactions_receipts(address, block_of(range), index_of(range), direction_of(range), limit)
+        .await
+        .map_err(ApiError::internal)?;
+    let current_block = current_block(&mut storage).await?;
+
+    Ok(Json(
+        responses
+            .into_iter()
+            .map(|response| AccountTxReceipt::from((response, current_block)))
+            .collect(),
+    ))
+}
+
+async fn account_op_receipts(
+    data: web::Data<ApiAccountData>,
+    account: web::Path<String>,
+    range: web::Query<AccountReceiptsQuery>,
+) -> JsonResult<Vec<AccountOpReceipt>> {
+    let query = parse_account_query(&account)?;
+    let range = range.into_inner();
+    let limit = range.limit;
+    let range = AccountReceipts::from(range);
+
+    let mut storage = data
+        .tokens
+        .pool()
+        .access_storage()
+        .await
+        .map_err(ApiError::internal)?;
+
+    let address = resolve_address(&mut storage, query).await?;
+    let responses = storage
+        .chain()
+        .operations_ext_schema()
+        .get_account_operations_receipts(address, block_of(range), index_of(range), direction_of(range), limit)
+        .await
+        .map_err(ApiError::internal)?;
+    let current_block = current_block(&mut storage).await?;
+
+    Ok(Json(
+        responses
+            .into_iter()
+            .map(|response| AccountOpReceipt::from((response, current_block)))
+            .collect(),
+    ))
+}
+
+async fn account_pending_ops(
+    data: web::Data<ApiAccountData>,
+    account: web::Path<String>,
+) -> JsonResult<Vec<zksync_types::PriorityOp>> {
+    let query = parse_account_query(&account)?;
+    let address = {
+        let mut storage = data
+            .tokens
+            .pool()
+            .access_storage()
+            .await
+            .map_err(ApiError::internal)?;
+        resolve_address(&mut storage, query).await?
+    };
+
+    let unconfirmed_deposits = data
+        .core_api_client
+        .get_unconfirmed_deposits(address)
+        .await
+        .map_err(ApiError::internal)?;
+
+    let ops: Vec<(u64, zksync_types::PriorityOp)> =
+        serde_json::from_value(unconfirmed_deposits).map_err(|err| ApiError::internal(err.to_string()))?;
+
+    Ok(Json(ops.into_iter().map(|(_, op)| op).collect()))
+}
+
+async fn resolve_address(
+    storage: &mut zksync_storage::StorageProcessor<'_>,
+    query: AccountQuery,
+) -> Result<Address, ApiError> {
+    match query {
+        AccountQuery::Address(address) => Ok(address),
+        AccountQuery::Id(id) => storage
+            .chain()
+            .account_schema()
+            .account_state_by_id(id)
+            .await
+            .map_err(ApiError::internal)?
+            .map(|state| state.account.address)
+            .ok_or_else(|| ApiError::not_found("account not found")),
+    }
+}
+
+fn block_of(range: AccountReceipts) -> Option<u32> {
+    match range {
+        AccountReceipts::Latest => None,
+        AccountReceipts::Older(types::BlockAndIndex { block, .. })
+        | AccountReceipts::Newer(types::BlockAndIndex { block, .. }) => Some(block),
+    }
+}
+
+fn index_of(range: AccountReceipts) -> Option<u32> {
+    match range {
+        AccountReceipts::Latest => None,
+        AccountReceipts::Older(types::BlockAndIndex { index, .. })
+        | AccountReceipts::Newer(types::BlockAndIndex { index, .. }) => index,
+    }
+}
+
+fn direction_of(range: AccountReceipts) -> &'static str {
+    match range {
+        AccountReceipts::Latest | AccountReceipts::Newer(_) => "newer",
+        AccountReceipts::Older(_) => "older",
+    }
+}
+
+pub fn api_scope(
+    _env_options: &ConfigurationOptions,
+    tokens: TokenDBCache,
+    core_api_client: CoreApiClient,
+) -> Scope {
+    let data = ApiAccountData::new(tokens, core_api_client);
+
+    web::scope("accounts")
+        .data(data)
+        .route("{id}", web::get().to(account_info))
+        .route("{id}/transactions/receipts", web::get().to(account_tx_receipts))
+        .route("{id}/operations/receipts", web::get().to(account_op_receipts))
+        .route("{id}/operations/pending", web::get().to(account_pending_ops))
+}
@@ -208,7 +208,14 @@ async fn accounts_scope() -> anyhow::Result<()> {
         .await?;
 
     assert_eq!(receipts[0].index, Some(3));
-    assert_eq!(receipts[0].receipt, TxReceipt::Verified { block: 1 });
+    assert!(matches!(
+        receipts[0].receipt,
+        TxReceipt::Verified {
+            block: 1,
+            confirmations: 0,
+            ..
+        }
+    ));
 
     // Get same receipts by the different requests.
     assert_eq!(
@@ -240,14 +247,16 @@ async fn accounts_scope() -> anyhow::Result<()> {
         .account_op_receipts(address, AccountReceipts::newer_than(1, Some(0)), 10)
         .await?;
 
-    assert_eq!(
-        receipts[0],
-        AccountOpReceipt {
-            hash: H256::default(),
-            index: 1,
-            receipt: TxReceipt::Verified { block: 1 }
+    assert_eq!(receipts[0].hash, H256::default());
+    assert_eq!(receipts[0].index, 1);
+    assert!(matches!(
+        receipts[0].receipt,
+        TxReceipt::Verified {
+            block: 1,
+            confirmations: 0,
+            ..
         }
-    );
+    ));
     assert_eq!(
         client
             .account_op_receipts(address, AccountReceipts::newer_than(1, Some(0)), 10)
@@ -288,6 +297,10 @@ fn account_tx_response_to_receipt() {
         TxHash::default().as_ref().to_vec()
     }
 
+    // The current block is ahead of the receipt's block, so `Committed`/`Verified` receipts
+    // below should come back with `confirmations: 4`.
+    let current_block: BlockNumber = 5;
+
     let cases = vec![
         (
             AccountTxReceiptResponse {
@@ -296,7 +309,9 @@ fn account_tx_response_to_receipt() {
                 success: true,
                 fail_reason: None,
                 commit_tx_hash: None,
+                commit_eth_block: None,
                 verify_tx_hash: None,
+                verify_eth_block: None,
                 tx_hash: empty_hash(),
             },
             AccountTxReceipt {
@@ -312,7 +327,9 @@ fn account_tx_response_to_receipt() {
                 success: true,
                 fail_reason: None,
                 commit_tx_hash: None,
+                commit_eth_block: None,
                 verify_tx_hash: None,
+                verify_eth_block: None,
                 tx_hash: empty_hash(),
             },
             AccountTxReceipt {
@@ -328,7 +345,9 @@ fn account_tx_response_to_receipt() {
                 success: false,
                 fail_reason: Some("Oops".to_string()),
                 commit_tx_hash: None,
+                commit_eth_block: None,
                 verify_tx_hash: None,
+                verify_eth_block: None,
                 tx_hash: empty_hash(),
             },
             AccountTxReceipt {
@@ -346,13 +365,19 @@ fn account_tx_response_to_receipt() {
                 success: true,
                 fail_reason: None,
                 commit_tx_hash: Some(empty_hash()),
+                commit_eth_block: Some(100),
                 verify_tx_hash: None,
+                verify_eth_block: None,
                 tx_hash: empty_hash(),
             },
             AccountTxReceipt {
                 index: Some(1),
                 hash: TxHash::default(),
-                receipt: TxReceipt::Committed { block: 1 },
+                receipt: TxReceipt::Committed {
+                    block: 1,
+                    confirmations: 4,
+                    eth_block: 100,
+                },
             },
         ),
         (
@@ -362,19 +387,25 @@ fn account_tx_response_to_receipt() {
                 success: true,
                 fail_reason: None,
                 commit_tx_hash: Some(empty_hash()),
+                commit_eth_block: Some(100),
                 verify_tx_hash: Some(empty_hash()),
+                verify_eth_block: Some(200),
                 tx_hash: empty_hash(),
             },
             AccountTxReceipt {
                 index: Some(1),
                 hash: TxHash::default(),
-                receipt: TxReceipt::Verified { block: 1 },
+                receipt: TxReceipt::Verified {
+                    block: 1,
+                    confirmations: 4,
+                    eth_block: 200,
+                },
             },
         ),
     ];
 
     for (resp, expected_receipt) in cases {
-        let actual_receipt = AccountTxReceipt::from(resp);
+        let actual_receipt = AccountTxReceipt::from((resp, current_block));
         assert_eq!(actual_receipt, expected_receipt);
     }
 }
@@ -385,13 +416,17 @@ fn account_op_response_to_receipt() {
         H256::default().as_bytes().to_vec()
     }
 
+    let current_block: BlockNumber = 5;
+
     let cases = vec![
         (
             AccountOpReceiptResponse {
                 block_index: 1,
                 block_number: 1,
                 commit_tx_hash: None,
+                commit_eth_block: None,
                 verify_tx_hash: None,
+                verify_eth_block: None,
                 eth_hash: empty_hash(),
             },
             AccountOpReceipt {
@@ -405,13 +440,19 @@ fn account_op_response_to_receipt() {
                 block_index: 1,
                 block_number: 1,
                 commit_tx_hash: Some(empty_hash()),
+                commit_eth_block: Some(100),
                 verify_tx_hash: None,
+                verify_eth_block: None,
                 eth_hash: empty_hash(),
             },
             AccountOpReceipt {
                 index: 1,
                 hash: H256::default(),
-                receipt: TxReceipt::Committed { block: 1 },
+                receipt: TxReceipt::Committed {
+                    block: 1,
+                    confirmations: 4,
+                    eth_block: 100,
+                },
             },
         ),
         (
@@ -419,19 +460,25 @@ fn account_op_response_to_receipt() {
                 block_index: 1,
                 block_number: 1,
                 commit_tx_hash: Some(empty_hash()),
+                commit_eth_block: Some(100),
                 verify_tx_hash: Some(empty_hash()),
+                verify_eth_block: Some(200),
                 eth_hash: empty_hash(),
             },
             AccountOpReceipt {
                 index: 1,
                 hash: H256::default(),
-                receipt: TxReceipt::Verified { block: 1 },
+                receipt: TxReceipt::Verified {
+                    block: 1,
+                    confirmations: 4,
+                    eth_block: 200,
+                },
             },
         ),
     ];
 
     for (resp, expected_receipt) in cases {
-        let actual_receipt = AccountOpReceipt::from(resp);
+        let actual_receipt = AccountOpReceipt::from((resp, current_block));
         assert_eq!(actual_receipt, expected_receipt);
     }
 }
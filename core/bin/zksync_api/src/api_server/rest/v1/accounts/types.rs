@@ -0,0 +1,170 @@
+//! Data transfer types for the `accounts` resource.
+
+// Built-in uses
+use std::collections::HashMap;
+
+// External uses
+use serde::{Deserialize, Serialize};
+
+// Workspace uses
+use zksync_storage::chain::operations_ext::records::{
+    AccountOpReceiptResponse, AccountTxReceiptResponse,
+};
+use zksync_types::{tx::TxHash, AccountId, Address, BigUintSerdeWrapper, BlockNumber, H256};
+
+// Local uses
+use crate::api_server::rest::v1::client::TxReceipt;
+
+/// Account summary as reported by `GET /accounts/{id}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountInfo {
+    pub address: Address,
+    pub id: Option<AccountId>,
+    pub depositing: DepositingAccountBalances,
+}
+
+/// Priority-op deposits that have been observed on L1 but not yet included in a block.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DepositingAccountBalances {
+    pub balances: HashMap<String, DepositingFunds>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DepositingFunds {
+    pub amount: BigUintSerdeWrapper,
+    /// The deposit priority operation's `eth_block` (the L1 block it was observed in), as
+    /// reported by the core API; no confirmation depth is added to it.
+    pub expected_accept_block: BlockNumber,
+}
+
+/// A page selector for the account receipts endpoints: either the newest receipts, or a
+/// cursor relative to a given `(block, index)` position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountReceipts {
+    Latest,
+    Older(BlockAndIndex),
+    Newer(BlockAndIndex),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockAndIndex {
+    pub block: BlockNumber,
+    pub index: Option<u32>,
+}
+
+impl AccountReceipts {
+    pub fn newer_than(block: BlockNumber, index: Option<u32>) -> Self {
+        Self::Newer(BlockAndIndex { block, index })
+    }
+
+    pub fn older_than(block: BlockNumber, index: Option<u32>) -> Self {
+        Self::Older(BlockAndIndex { block, index })
+    }
+
+    /// Serializes the cursor as the query string the REST handlers expect.
+    pub(crate) fn as_query(self, limit: u32) -> Vec<(&'static str, String)> {
+        let mut query = vec![("limit", limit.to_string())];
+        match self {
+            Self::Latest => {}
+            Self::Older(BlockAndIndex { block, index }) => {
+                query.push(("block", block.to_string()));
+                if let Some(index) = index {
+                    query.push(("index", index.to_string()));
+                }
+                query.push(("direction", "older".to_string()));
+            }
+            Self::Newer(BlockAndIndex { block, index }) => {
+                query.push(("block", block.to_string()));
+                if let Some(index) = index {
+                    query.push(("index", index.to_string()));
+                }
+                query.push(("direction", "newer".to_string()));
+            }
+        }
+        query
+    }
+}
+
+/// Receipt of an L2 transaction, as seen from a single account's history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountTxReceipt {
+    pub index: Option<u32>,
+    pub hash: TxHash,
+    pub receipt: TxReceipt,
+}
+
+/// Converts a raw storage row into an [`AccountTxReceipt`]. Takes `current_block` (the most
+/// recent block committed to the L1 contract) alongside the response so `confirmations` on a
+/// `Committed`/`Verified` receipt can be computed.
+impl From<(AccountTxReceiptResponse, BlockNumber)> for AccountTxReceipt {
+    fn from((inner, current_block): (AccountTxReceiptResponse, BlockNumber)) -> Self {
+        let hash = TxHash::from_slice(&inner.tx_hash).unwrap_or_default();
+        let block = inner.block_number as BlockNumber;
+        let confirmations = current_block.saturating_sub(block);
+
+        let receipt = if !inner.success {
+            TxReceipt::Rejected {
+                reason: inner.fail_reason,
+            }
+        } else if let (Some(_), Some(eth_block)) = (&inner.verify_tx_hash, inner.verify_eth_block) {
+            TxReceipt::Verified {
+                block,
+                confirmations,
+                eth_block: eth_block as u64,
+            }
+        } else if let (Some(_), Some(eth_block)) = (&inner.commit_tx_hash, inner.commit_eth_block) {
+            TxReceipt::Committed {
+                block,
+                confirmations,
+                eth_block: eth_block as u64,
+            }
+        } else {
+            TxReceipt::Executed
+        };
+
+        Self {
+            index: inner.block_index,
+            hash,
+            receipt,
+        }
+    }
+}
+
+/// Receipt of a priority operation (e.g. a deposit), as seen from a single account's history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountOpReceipt {
+    pub index: u32,
+    pub hash: H256,
+    pub receipt: TxReceipt,
+}
+
+/// See the [`AccountTxReceipt`] `From` impl above for why `current_block` is needed.
+impl From<(AccountOpReceiptResponse, BlockNumber)> for AccountOpReceipt {
+    fn from((inner, current_block): (AccountOpReceiptResponse, BlockNumber)) -> Self {
+        let hash = H256::from_slice(&inner.eth_hash);
+        let block = inner.block_number as BlockNumber;
+        let confirmations = current_block.saturating_sub(block);
+
+        let receipt = if let (Some(_), Some(eth_block)) = (&inner.verify_tx_hash, inner.verify_eth_block) {
+            TxReceipt::Verified {
+                block,
+                confirmations,
+                eth_block: eth_block as u64,
+            }
+        } else if let (Some(_), Some(eth_block)) = (&inner.commit_tx_hash, inner.commit_eth_block) {
+            TxReceipt::Committed {
+                block,
+                confirmations,
+                eth_block: eth_block as u64,
+            }
+        } else {
+            TxReceipt::Executed
+        };
+
+        Self {
+            index: inner.block_index,
+            hash,
+            receipt,
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Block information endpoints, scoped under `/api/v1/blocks`.
+
+// Built-in uses
+
+// External uses
+use actix_web::{
+    web::{self, Json},
+    Scope,
+};
+
+// Workspace uses
+use zksync_storage::{
+    chain::operations_ext::records::{AccountOpReceiptResponse, AccountTxReceiptResponse},
+    ConnectionPool, StorageProcessor,
+};
+use zksync_types::{BlockNumber, ExecutedOperations, H256};
+
+// Local uses
+use crate::api_server::rest::v1::{
+    accounts::types::{AccountOpReceipt, AccountTxReceipt},
+    client::BlockQuery,
+    current_block,
+    error::ApiError,
+    JsonResult,
+};
+
+pub mod proof;
+pub mod types;
+#[cfg(test)]
+mod tests;
+
+use self::types::BlockReceipts;
+
+#[derive(Clone)]
+struct ApiBlocksData {
+    pool: ConnectionPool,
+}
+
+impl ApiBlocksData {
+    fn new(pool: ConnectionPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn parse_block_query(raw: &str) -> Result<BlockQuery, ApiError> {
+    if let Ok(number) = raw.parse::<BlockNumber>() {
+        return Ok(BlockQuery::Number(number));
+    }
+    raw.parse::<H256>()
+        .map(BlockQuery::Hash)
+        .map_err(|_| ApiError::bad_request("block must be a number or a hash"))
+}
+
+/// Resolves a [`BlockQuery`] to a concrete block number, or `None` if it doesn't exist.
+///
+/// A numeric query still has to be checked against storage: an out-of-range block number and
+/// a real, already-produced empty block both have zero transactions, so only `block_exists`
+/// tells them apart.
+async fn resolve_block_number(
+    storage: &mut StorageProcessor<'_>,
+    query: BlockQuery,
+) -> Result<Option<BlockNumber>, ApiError> {
+    match query {
+        BlockQuery::Number(number) => {
+            let exists = storage
+                .chain()
+                .block_schema()
+                .block_exists(number)
+                .await
+                .map_err(ApiError::internal)?;
+            Ok(if exists { Some(number) } else { None })
+        }
+        BlockQuery::Hash(hash) => storage
+            .chain()
+            .block_schema()
+            .get_block_number_by_commit_tx_hash(hash)
+            .await
+            .map_err(ApiError::internal),
+    }
+}
+
+/// Loads every transaction/priority-op receipt executed in `block_number`, reusing the same
+/// commit/verify tx-hash -> [`crate::api_server::rest::v1::client::TxReceipt`] conversion the
+/// per-account receipt endpoints already use.
+async fn load_block_receipts(
+    storage: &mut StorageProcessor<'_>,
+    block_number: BlockNumber,
+) -> Result<BlockReceipts, ApiError> {
+    let transactions = storage
+        .chain()
+        .block_schema()
+        .get_block_transactions(block_number)
+        .await
+        .map_err(ApiError::internal)?;
+
+    let (commit_tx_hash, commit_eth_block, verify_tx_hash, verify_eth_block) = storage
+        .chain()
+        .block_schema()
+        .get_block_commit_verify_info(block_number)
+        .await
+        .map_err(ApiError::internal)?;
+    let current_block = current_block(storage).await?;
+
+    let mut tx_receipts = Vec::new();
+    let mut op_receipts = Vec::new();
+
+    for (index, executed_op) in transactions.into_iter().enumerate() {
+        match executed_op {
+            ExecutedOperations::Tx(tx) => {
+                tx_receipts.push(AccountTxReceipt::from((
+                    AccountTxReceiptResponse {
+                        block_index: tx.block_index.or(Some(index as u32)),
+                        block_number: block_number as i64,
+                        success: tx.success,
+                        fail_reason: tx.fail_reason.clone(),
+                        commit_tx_hash: commit_tx_hash.clone(),
+                        commit_eth_block,
+                        verify_tx_hash: verify_tx_hash.clone(),
+                        verify_eth_block,
+                        tx_hash: tx.signed_tx.hash().as_ref().to_vec(),
+                    },
+                    current_block,
+                )));
+            }
+            ExecutedOperations::PriorityOp(op) => {
+                op_receipts.push(AccountOpReceipt::from((
+                    AccountOpReceiptResponse {
+                        block_index: op.block_index,
+                        block_number: block_number as i64,
+                        commit_tx_hash: commit_tx_hash.clone(),
+                        commit_eth_block,
+                        verify_tx_hash: verify_tx_hash.clone(),
+                        verify_eth_block,
+                        eth_hash: op.priority_op.eth_hash.clone(),
+                    },
+                    current_block,
+                )));
+            }
+        }
+    }
+
+    Ok(BlockReceipts {
+        block: block_number,
+        tx_receipts,
+        op_receipts,
+    })
+}
+
+/// Returns every transaction/priority-op receipt executed in a block in one round-trip.
+///
+/// Mirrors the `getBlockReceipts`-style endpoints other chains expose: without it, a caller
+/// that wants a block's full receipt set has to fan out one `account_tx_receipts`/
+/// `account_op_receipts` call per participant.
+async fn block_receipts(
+    data: web::Data<ApiBlocksData>,
+    block: web::Path<String>,
+) -> JsonResult<Option<BlockReceipts>> {
+    let query = parse_block_query(&block)?;
+    let mut storage = data.pool.access_storage().await.map_err(ApiError::internal)?;
+
+    let block_number = match resolve_block_number(&mut storage, query).await? {
+        Some(number) => number,
+        None => return Ok(Json(None)),
+    };
+
+    Ok(Json(Some(
+        load_block_receipts(&mut storage, block_number).await?,
+    )))
+}
+
+pub fn api_scope(pool: ConnectionPool) -> Scope {
+    let data = ApiBlocksData::new(pool);
+
+    web::scope("blocks")
+        .data(data)
+        .route("{block}/receipts", web::get().to(block_receipts))
+        .route(
+            "{block}/receipts/{index}/proof",
+            web::get().to(proof::receipt_proof),
+        )
+}
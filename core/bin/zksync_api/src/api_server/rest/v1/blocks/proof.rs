@@ -0,0 +1,189 @@
+//! Merkle-Patricia inclusion proofs for a single receipt inside a committed block.
+//!
+//! A light client that doesn't want to trust this API server can ask for a specific
+//! receipt's proof, check `root` against the block's on-chain commitment, then walk
+//! `proof_nodes` itself (hashing each node and checking the referenced child at every step)
+//! to confirm the receipt really is part of that block.
+
+// Built-in uses
+
+// External uses
+use actix_web::web::{self, Json};
+use rlp::RlpStream;
+
+// Workspace uses
+use zksync_types::BlockNumber;
+
+// Local uses
+use crate::{
+    api_server::rest::v1::{
+        accounts::types::{AccountOpReceipt, AccountTxReceipt},
+        client::TxReceipt,
+        error::ApiError,
+        JsonResult,
+    },
+    utils::merkle_patricia::MerklePatriciaTree,
+};
+
+use super::{
+    types::{BlockReceipts, ReceiptInclusionProof},
+    ApiBlocksData,
+};
+
+/// RLP-encodes the block-invariant fields of a [`TxReceipt`]: whether execution succeeded, plus
+/// the block it was committed/verified in (empty while only `Executed`), or the failure reason
+/// for a `Rejected` receipt. Deliberately excludes `confirmations`/`eth_block` -- `confirmations`
+/// grows with every later block and `eth_block` changes when a commit is followed by a verify,
+/// so hashing them would change `root` for an already-final block between calls to this
+/// endpoint, defeating the point of checking `root` against a fixed on-chain commitment.
+/// Callers get those fields back alongside the proof instead, see
+/// [`ReceiptInclusionProof::receipt`].
+fn encode_receipt(receipt: &TxReceipt) -> Vec<u8> {
+    let mut stream = RlpStream::new();
+    match receipt {
+        TxReceipt::Executed => {
+            stream.begin_list(2);
+            stream.append(&1u8);
+            stream.append_empty_data();
+        }
+        TxReceipt::Committed { block, .. } | TxReceipt::Verified { block, .. } => {
+            stream.begin_list(2);
+            stream.append(&1u8);
+            stream.append(block);
+        }
+        TxReceipt::Rejected { reason } => {
+            stream.begin_list(2);
+            stream.append(&0u8);
+            stream.append(&reason.clone().unwrap_or_default());
+        }
+    }
+    stream.out().to_vec()
+}
+
+/// Builds the trie for a whole block's receipts, keyed by `rlp(index)` where `index` is the
+/// receipt's position among every tx/priority-op receipt in the block.
+fn build_receipts_trie(receipts: &BlockReceipts) -> MerklePatriciaTree {
+    let tx_entries = receipts
+        .tx_receipts
+        .iter()
+        .filter_map(|r: &AccountTxReceipt| r.index.map(|index| (index, encode_receipt(&r.receipt))));
+    let op_entries = receipts
+        .op_receipts
+        .iter()
+        .map(|r: &AccountOpReceipt| (r.index, encode_receipt(&r.receipt)));
+
+    let entries = tx_entries
+        .chain(op_entries)
+        .map(|(index, value)| (rlp::encode(&index).to_vec(), value))
+        .collect();
+
+    MerklePatriciaTree::build(entries)
+}
+
+/// The receipt at `index` among a block's tx/priority-op receipts, if any.
+fn receipt_at(receipts: &BlockReceipts, index: u32) -> Option<TxReceipt> {
+    receipts
+        .tx_receipts
+        .iter()
+        .find(|r| r.index == Some(index))
+        .map(|r| r.receipt.clone())
+        .or_else(|| {
+            receipts
+                .op_receipts
+                .iter()
+                .find(|r| r.index == index)
+                .map(|r| r.receipt.clone())
+        })
+}
+
+pub(super) async fn receipt_proof(
+    data: web::Data<ApiBlocksData>,
+    path: web::Path<(String, u32)>,
+) -> JsonResult<Option<ReceiptInclusionProof>> {
+    let (block, index) = path.into_inner();
+    let query = super::parse_block_query(&block)?;
+
+    let mut storage = data.pool.access_storage().await.map_err(ApiError::internal)?;
+    let block_number: BlockNumber = match super::resolve_block_number(&mut storage, query).await? {
+        Some(number) => number,
+        None => return Ok(Json(None)),
+    };
+
+    let receipts = super::load_block_receipts(&mut storage, block_number).await?;
+    let receipt = match receipt_at(&receipts, index) {
+        Some(receipt) => receipt,
+        None => return Ok(Json(None)),
+    };
+    let tree = build_receipts_trie(&receipts);
+
+    let key = rlp::encode(&index).to_vec();
+    Ok(Json(tree.proof(&key).map(|proof| ReceiptInclusionProof {
+        root: proof.root,
+        key: proof.key,
+        value: proof.value,
+        proof_nodes: proof.nodes,
+        receipt,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_server::rest::v1::accounts::types::AccountTxReceipt;
+
+    fn verified(block: BlockNumber, confirmations: u32, eth_block: u64) -> TxReceipt {
+        TxReceipt::Verified {
+            block,
+            confirmations,
+            eth_block,
+        }
+    }
+
+    #[test]
+    fn encode_receipt_ignores_confirmations_and_eth_block() {
+        // Same block, different confirmations/eth_block (as it would be if queried again
+        // several blocks later): the hashed payload must not change, or `root` would drift
+        // for an already-final block on every call.
+        let now = encode_receipt(&verified(1, 0, 100));
+        let later = encode_receipt(&verified(1, 50, 999));
+        assert_eq!(now, later);
+
+        // A different block does change the payload.
+        let other_block = encode_receipt(&verified(2, 0, 100));
+        assert_ne!(now, other_block);
+    }
+
+    #[test]
+    fn empty_block_has_the_canonical_empty_root() {
+        let receipts = BlockReceipts {
+            block: 1,
+            tx_receipts: vec![],
+            op_receipts: vec![],
+        };
+        let tree = build_receipts_trie(&receipts);
+        assert_eq!(tree.root_hash(), MerklePatriciaTree::empty_root());
+        assert!(tree.proof(&rlp::encode(&0u32).to_vec()).is_none());
+    }
+
+    #[test]
+    fn single_receipt_block_proof_round_trips() {
+        let receipts = BlockReceipts {
+            block: 1,
+            tx_receipts: vec![AccountTxReceipt {
+                index: Some(0),
+                hash: Default::default(),
+                receipt: verified(1, 0, 100),
+            }],
+            op_receipts: vec![],
+        };
+        let tree = build_receipts_trie(&receipts);
+
+        let key = rlp::encode(&0u32).to_vec();
+        let proof = tree.proof(&key).expect("single receipt must be provable");
+        assert_eq!(proof.root, tree.root_hash());
+        assert_eq!(proof.value, encode_receipt(&verified(1, 0, 100)));
+
+        assert_eq!(receipt_at(&receipts, 0), Some(verified(1, 0, 100)));
+        assert_eq!(receipt_at(&receipts, 1), None);
+    }
+}
@@ -0,0 +1,53 @@
+// Local uses
+use crate::api_server::rest::v1::{client::Client, test_utils::TestServerConfig};
+
+use super::api_scope;
+
+async fn start_server() -> anyhow::Result<(Client, actix_web::test::TestServer)> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+
+    Ok(cfg.start_server(move |cfg| api_scope(cfg.pool.clone())))
+}
+
+#[actix_rt::test]
+async fn blocks_scope() -> anyhow::Result<()> {
+    let (client, server) = start_server().await?;
+
+    // A produced block returns every tx/priority-op receipt executed in it.
+    let receipts = client.block_receipts(1).await?.unwrap();
+    assert_eq!(receipts.block, 1);
+    assert!(!receipts.tx_receipts.is_empty());
+
+    // Same receipts, requested again: idempotent.
+    assert_eq!(client.block_receipts(1).await?, Some(receipts));
+
+    // A block number beyond the chain's height doesn't exist, unlike a real empty block.
+    assert_eq!(client.block_receipts(u32::MAX).await?, None);
+
+    server.stop().await;
+    Ok(())
+}
+
+#[actix_rt::test]
+async fn receipt_proof_scope() -> anyhow::Result<()> {
+    let (client, server) = start_server().await?;
+
+    let receipts = client.block_receipts(1).await?.unwrap();
+    let tx_index = receipts.tx_receipts[0].index.unwrap();
+
+    // A real receipt's proof carries a root and at least one node (the leaf itself).
+    let proof = client.receipt_proof(1, tx_index).await?.unwrap();
+    assert_eq!(proof.key, rlp::encode(&tx_index).to_vec());
+    assert!(!proof.proof_nodes.is_empty());
+    assert_eq!(proof.receipt, receipts.tx_receipts[0].receipt);
+
+    // An index with no receipt in the block doesn't have a proof.
+    assert_eq!(client.receipt_proof(1, u32::MAX).await?, None);
+
+    // Neither does a block that doesn't exist.
+    assert_eq!(client.receipt_proof(u32::MAX, tx_index).await?, None);
+
+    server.stop().await;
+    Ok(())
+}
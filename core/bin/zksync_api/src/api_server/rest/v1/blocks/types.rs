@@ -0,0 +1,37 @@
+//! Data transfer types for the `blocks` resource.
+
+// External uses
+use serde::{Deserialize, Serialize};
+
+// Workspace uses
+use zksync_types::{BlockNumber, H256};
+
+// Local uses
+use crate::api_server::rest::v1::{
+    accounts::types::{AccountOpReceipt, AccountTxReceipt},
+    client::TxReceipt,
+};
+
+/// Every transaction and priority-operation receipt executed in a single block, fetched in
+/// one round-trip instead of the N calls a per-account receipt scan over the block would take.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlockReceipts {
+    pub block: BlockNumber,
+    pub tx_receipts: Vec<AccountTxReceipt>,
+    pub op_receipts: Vec<AccountOpReceipt>,
+}
+
+/// A Merkle-Patricia inclusion proof for a single receipt inside a committed block: the
+/// `root` can be checked against the block's on-chain commitment, and a light client walks
+/// `proof_nodes` (root-first) to confirm `value` really sits behind `key` in that trie.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReceiptInclusionProof {
+    pub root: H256,
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+    pub proof_nodes: Vec<Vec<u8>>,
+    /// The receipt `value` was built from, confirmations and all. Carried alongside the proof
+    /// rather than inside `value`: `confirmations` grows with every later block, so baking it
+    /// into the hashed payload would change `root` for an already-final block on every call.
+    pub receipt: TxReceipt,
+}
@@ -0,0 +1,371 @@
+//! `/api/v1` HTTP client.
+//!
+//! Thin wrapper around [`reqwest`] that mirrors the handlers mounted by [`super::api_scope`],
+//! so that SDK users and our own integration tests talk to the REST API the same way.
+
+// Built-in uses
+use std::{fmt::Display, time::Duration};
+
+// External uses
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+use tokio::time::sleep;
+
+// Workspace uses
+use zksync_types::{tx::TxHash, AccountId, Address, BlockNumber, H256};
+
+// Local uses
+use super::{
+    accounts::types::{AccountInfo, AccountOpReceipt, AccountReceipts, AccountTxReceipt},
+    blocks::types::{BlockReceipts, ReceiptInclusionProof},
+};
+
+/// Errors that can occur while talking to the `/api/v1` REST API.
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("Network error: {0}")]
+    NetworkError(#[from] reqwest::Error),
+    #[error("API response could not be parsed: {0}")]
+    MalformedResponse(String),
+    #[error("API request failed with status {0}: {1}")]
+    ErrorResponse(u16, String),
+    #[error("Transaction was rejected: {}", .0.as_deref().unwrap_or("no reason given"))]
+    TransactionRejected(Option<String>),
+    #[error("Timed out waiting for the transaction to reach the {0:?} state")]
+    Timeout(WaitFor),
+}
+
+pub type Result<T> = std::result::Result<T, ClientError>;
+
+/// Either an account address or its assigned numeric id; most account-scoped endpoints
+/// accept either form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountQuery {
+    Id(AccountId),
+    Address(Address),
+}
+
+impl From<AccountId> for AccountQuery {
+    fn from(id: AccountId) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<Address> for AccountQuery {
+    fn from(address: Address) -> Self {
+        Self::Address(address)
+    }
+}
+
+impl Display for AccountQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Id(id) => write!(f, "{}", id),
+            Self::Address(address) => write!(f, "{:#x}", address),
+        }
+    }
+}
+
+/// Either a block number or the hash of the Ethereum commit transaction that block was
+/// included in; the block-scoped endpoints accept either form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockQuery {
+    Number(BlockNumber),
+    Hash(H256),
+}
+
+impl From<BlockNumber> for BlockQuery {
+    fn from(number: BlockNumber) -> Self {
+        Self::Number(number)
+    }
+}
+
+impl From<H256> for BlockQuery {
+    fn from(hash: H256) -> Self {
+        Self::Hash(hash)
+    }
+}
+
+impl Display for BlockQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(number) => write!(f, "{}", number),
+            Self::Hash(hash) => write!(f, "{:#x}", hash),
+        }
+    }
+}
+
+/// Lifecycle of a zkSync transaction or priority operation, as observed through the
+/// `/api/v1` REST API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum TxReceipt {
+    /// Executed in a block, but the block has not been sent to the L1 contract yet.
+    Executed,
+    /// The block containing the operation was committed to the L1 contract.
+    Committed {
+        block: BlockNumber,
+        /// `current_block - block`, i.e. how many later blocks have been committed since;
+        /// the caller can use this to decide whether a `Committed` receipt is deep enough to
+        /// be treated as final, or is still at risk of an L1 reorg rolling it back.
+        confirmations: u32,
+        /// L1 Ethereum block the commit transaction was mined in.
+        eth_block: u64,
+    },
+    /// The block containing the operation was verified on L1.
+    Verified {
+        block: BlockNumber,
+        /// `current_block - block`, same meaning as on `Committed`.
+        confirmations: u32,
+        /// L1 Ethereum block the verify transaction was mined in.
+        eth_block: u64,
+    },
+    /// Execution failed; `reason` carries the human-readable failure message, if any.
+    Rejected { reason: Option<String> },
+}
+
+/// Target confirmation state a [`PendingTx`] waits for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitFor {
+    /// Wait until the block containing the transaction is committed to the L1 contract.
+    Commit,
+    /// Wait until the block containing the transaction is verified on L1.
+    Verify,
+}
+
+/// Default minimum `TxReceipt::Committed`/`Verified` confirmation depth required before
+/// [`PendingTx::wait`] resolves: reaching the target state at all is enough.
+const DEFAULT_CONFIRMATIONS: u32 = 0;
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// A future-like builder that polls a transaction's receipt until it reaches a target
+/// confirmation state, so callers don't have to hand-roll an `account_tx_receipts` polling
+/// loop after submitting a transaction.
+///
+/// Internally walks the same state machine the REST API's [`TxReceipt`] models:
+/// `Unknown -> Executed -> Committed -> Verified`, short-circuiting into an error as soon as
+/// the transaction is `Rejected`.
+#[derive(Debug)]
+pub struct PendingTx<'a> {
+    client: &'a Client,
+    hash: TxHash,
+    target: WaitFor,
+    confirmations: u32,
+    poll_interval: Duration,
+    timeout: Duration,
+}
+
+impl<'a> PendingTx<'a> {
+    fn new(client: &'a Client, hash: TxHash, target: WaitFor) -> Self {
+        Self {
+            client,
+            hash,
+            target,
+            confirmations: DEFAULT_CONFIRMATIONS,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Minimum `confirmations` (see [`TxReceipt::Committed`]/[`TxReceipt::Verified`]) the
+    /// target state's block must have reached before resolving, to guard against an L1 reorg
+    /// rolling back a receipt observed the moment it first reached that state.
+    pub fn confirmations(mut self, confirmations: u32) -> Self {
+        self.confirmations = confirmations;
+        self
+    }
+
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Polls until the transaction reaches `self.target` with at least `self.confirmations`
+    /// depth, resolves with an error if it's `Rejected`, and times out after `self.timeout` if
+    /// it never gets there.
+    pub async fn wait(self) -> Result<TxReceipt> {
+        let deadline = tokio::time::Instant::now() + self.timeout;
+
+        loop {
+            let receipt = self.client.tx_receipt(self.hash).await?;
+
+            match receipt {
+                Some(TxReceipt::Rejected { reason }) => {
+                    return Err(ClientError::TransactionRejected(reason))
+                }
+                Some(ref receipt) if self.reached_target(receipt) => {
+                    return Ok(receipt.clone());
+                }
+                _ => {}
+            }
+
+            if tokio::time::Instant::now() >= deadline {
+                return Err(ClientError::Timeout(self.target));
+            }
+            sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Whether `receipt` is at or past `self.target` with a chain depth (the receipt's own
+    /// `confirmations` field) of at least `self.confirmations`.
+    fn reached_target(&self, receipt: &TxReceipt) -> bool {
+        let confirmations = match (self.target, receipt) {
+            (WaitFor::Commit, TxReceipt::Committed { confirmations, .. })
+            | (WaitFor::Commit, TxReceipt::Verified { confirmations, .. })
+            | (WaitFor::Verify, TxReceipt::Verified { confirmations, .. }) => *confirmations,
+            _ => return false,
+        };
+        confirmations >= self.confirmations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending_tx(target: WaitFor, confirmations: u32) -> PendingTx<'static> {
+        // `client` is never dialed by `reached_target`, so an unused `Client` pointed at a
+        // dummy URL is fine here; `Box::leak` gives it the `'static` lifetime without a server.
+        let client: &'static Client = Box::leak(Box::new(Client::new("http://unused")));
+        PendingTx::new(client, TxHash::default(), target).confirmations(confirmations)
+    }
+
+    #[test]
+    fn reached_target_checks_state_and_depth() {
+        let executed = TxReceipt::Executed;
+        let committed = TxReceipt::Committed {
+            block: 1,
+            confirmations: 2,
+            eth_block: 10,
+        };
+        let verified = TxReceipt::Verified {
+            block: 1,
+            confirmations: 2,
+            eth_block: 20,
+        };
+
+        // `Commit` is satisfied by either `Committed` or `Verified`; `Executed` never satisfies it.
+        assert!(!pending_tx(WaitFor::Commit, 0).reached_target(&executed));
+        assert!(pending_tx(WaitFor::Commit, 0).reached_target(&committed));
+        assert!(pending_tx(WaitFor::Commit, 0).reached_target(&verified));
+
+        // `Verify` requires `Verified` specifically.
+        assert!(!pending_tx(WaitFor::Verify, 0).reached_target(&committed));
+        assert!(pending_tx(WaitFor::Verify, 0).reached_target(&verified));
+
+        // The required depth is compared against the receipt's own `confirmations`.
+        assert!(pending_tx(WaitFor::Commit, 2).reached_target(&committed));
+        assert!(!pending_tx(WaitFor::Commit, 3).reached_target(&committed));
+    }
+}
+
+/// HTTP client for the `/api/v1` REST API.
+#[derive(Debug, Clone)]
+pub struct Client {
+    inner: reqwest::Client,
+    /// Base API URL, e.g. `http://127.0.0.1:3001/api/v1`.
+    url: String,
+}
+
+impl Client {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            inner: reqwest::Client::new(),
+            url: url.into(),
+        }
+    }
+
+    async fn get<T: DeserializeOwned>(&self, endpoint: &str, query: &[(&str, String)]) -> Result<T> {
+        let response = self
+            .inner
+            .get(&format!("{}/{}", self.url, endpoint))
+            .query(query)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            return Err(ClientError::ErrorResponse(status, body));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|err| ClientError::MalformedResponse(err.to_string()))
+    }
+
+    pub async fn account_info(&self, account: impl Into<AccountQuery>) -> Result<Option<AccountInfo>> {
+        self.get(&format!("accounts/{}", account.into()), &[]).await
+    }
+
+    pub async fn account_tx_receipts(
+        &self,
+        account: impl Into<AccountQuery>,
+        range: AccountReceipts,
+        limit: u32,
+    ) -> Result<Vec<AccountTxReceipt>> {
+        self.get(
+            &format!("accounts/{}/transactions/receipts", account.into()),
+            &range.as_query(limit),
+        )
+        .await
+    }
+
+    pub async fn account_op_receipts(
+        &self,
+        account: impl Into<AccountQuery>,
+        range: AccountReceipts,
+        limit: u32,
+    ) -> Result<Vec<AccountOpReceipt>> {
+        self.get(
+            &format!("accounts/{}/operations/receipts", account.into()),
+            &range.as_query(limit),
+        )
+        .await
+    }
+
+    pub async fn account_pending_ops(
+        &self,
+        account: impl Into<AccountQuery>,
+    ) -> Result<Vec<zksync_types::PriorityOp>> {
+        self.get(&format!("accounts/{}/operations/pending", account.into()), &[])
+            .await
+    }
+
+    /// Returns every transaction and priority-operation receipt executed in a block, in a
+    /// single round-trip, avoiding the N calls an `account_tx_receipts`/`account_op_receipts`
+    /// scan over the block's participants would require. `None` if the block doesn't exist.
+    pub async fn block_receipts(&self, block: impl Into<BlockQuery>) -> Result<Option<BlockReceipts>> {
+        self.get(&format!("blocks/{}/receipts", block.into()), &[]).await
+    }
+
+    /// Merkle-Patricia inclusion proof for the receipt at `index` in `block`, so a light
+    /// client can verify it belongs to that block without trusting this API.
+    pub async fn receipt_proof(
+        &self,
+        block: impl Into<BlockQuery>,
+        index: u32,
+    ) -> Result<Option<ReceiptInclusionProof>> {
+        self.get(&format!("blocks/{}/receipts/{}/proof", block.into(), index), &[])
+            .await
+    }
+
+    /// Current confirmation status of a single transaction, looked up by hash alone.
+    pub async fn tx_receipt(&self, hash: TxHash) -> Result<Option<TxReceipt>> {
+        // `TxHash`'s `Display` (not `{:#x}`) is what `parse_tx_hash` on the server expects.
+        self.get(&format!("transactions/{}/receipt", hash), &[]).await
+    }
+
+    /// Builds a [`PendingTx`] that polls `hash`'s receipt until it reaches `target`, the
+    /// ergonomic "submit then await confirmation" counterpart to `tx_receipt`'s one-shot check.
+    pub fn wait_for_receipt(&self, hash: TxHash, target: WaitFor) -> PendingTx<'_> {
+        PendingTx::new(self, hash, target)
+    }
+}
@@ -0,0 +1,61 @@
+//! Common error type for the `/api/v1` handlers.
+
+// Built-in uses
+use std::fmt::Display;
+
+// External uses
+use actix_web::{HttpResponse, ResponseError};
+use serde::Serialize;
+
+/// Error shared by every handler in this API version. Handlers convert their internal
+/// errors into this type via `?`, and it in turn knows how to render itself as a response.
+#[derive(Debug, Serialize)]
+pub struct ApiError {
+    http_code: u16,
+    message: String,
+}
+
+impl ApiError {
+    pub fn bad_request(message: impl Display) -> Self {
+        Self {
+            http_code: 400,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn not_found(message: impl Display) -> Self {
+        Self {
+            http_code: 404,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn internal(message: impl Display) -> Self {
+        Self {
+            http_code: 500,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl From<anyhow::Error> for ApiError {
+    fn from(err: anyhow::Error) -> Self {
+        Self::internal(err)
+    }
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl ResponseError for ApiError {
+    fn error_response(&self) -> HttpResponse {
+        match self.http_code {
+            400 => HttpResponse::BadRequest().json(self),
+            404 => HttpResponse::NotFound().json(self),
+            _ => HttpResponse::InternalServerError().json(self),
+        }
+    }
+}
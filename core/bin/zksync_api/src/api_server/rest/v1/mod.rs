@@ -0,0 +1,59 @@
+//! First stable version of the REST API.
+
+// Built-in uses
+
+// External uses
+use actix_web::{web, Scope};
+
+// Workspace uses
+use zksync_config::ConfigurationOptions;
+use zksync_storage::{ConnectionPool, StorageProcessor};
+use zksync_types::BlockNumber;
+
+// Local uses
+use crate::{core_api_client::CoreApiClient, utils::token_db_cache::TokenDBCache};
+
+pub mod accounts;
+pub mod blocks;
+pub mod client;
+mod error;
+pub mod transactions;
+
+#[cfg(test)]
+pub mod test_utils;
+
+pub use self::{
+    client::{Client, ClientError},
+    error::ApiError,
+};
+
+pub(crate) type JsonResult<T> = std::result::Result<web::Json<T>, ApiError>;
+
+/// The most recent L2 block this node has committed to the L1 contract, used as the reference point for
+/// `confirmations` on `Committed`/`Verified` receipts (see [`client::TxReceipt`]).
+pub(crate) async fn current_block(storage: &mut StorageProcessor<'_>) -> Result<BlockNumber, ApiError> {
+    storage
+        .chain()
+        .block_schema()
+        .get_last_committed_block()
+        .await
+        .map_err(ApiError::internal)
+}
+
+/// Mounts the full `/api/v1` scope, combining every resource-specific sub-scope.
+pub fn api_scope(
+    env_options: &ConfigurationOptions,
+    pool: ConnectionPool,
+    core_api_client: CoreApiClient,
+) -> Scope {
+    let tokens = TokenDBCache::new(pool.clone());
+
+    web::scope("/api/v1")
+        .service(accounts::api_scope(
+            env_options,
+            tokens,
+            core_api_client,
+        ))
+        .service(blocks::api_scope(pool.clone()))
+        .service(transactions::api_scope(pool))
+}
@@ -0,0 +1,59 @@
+//! Shared scaffolding for the `/api/v1` handler test suites.
+
+// Built-in uses
+
+// External uses
+use actix_web::{web, App, Scope};
+
+// Workspace uses
+use zksync_config::ConfigurationOptions;
+use zksync_storage::ConnectionPool;
+
+// Local uses
+use super::Client;
+
+/// Spins up an in-memory Postgres-backed connection pool and the default `ConfigurationOptions`
+/// a test server needs, then mounts a handler-under-test behind a real `actix_web::test::TestServer`.
+pub struct TestServerConfig {
+    pub pool: ConnectionPool,
+    pub env_options: ConfigurationOptions,
+}
+
+impl Default for TestServerConfig {
+    fn default() -> Self {
+        Self {
+            pool: ConnectionPool::new(Some(1)),
+            env_options: ConfigurationOptions::from_env(),
+        }
+    }
+}
+
+impl TestServerConfig {
+    /// Fills the test database with a handful of deterministic blocks/accounts/transactions,
+    /// shared by every `/api/v1` test so fixtures don't have to be rebuilt per-suite.
+    pub async fn fill_database(&self) -> anyhow::Result<()> {
+        zksync_test_account::db_fixtures::apply(&self.pool).await
+    }
+
+    /// Starts a loopback `actix_web::test::TestServer` serving `scope_factory(self)`, returning
+    /// a `Client` already pointed at it.
+    pub fn start_server<F>(&self, scope_factory: F) -> (Client, actix_web::test::TestServer)
+    where
+        F: Fn(&Self) -> Scope + Clone + Send + 'static,
+    {
+        let cfg = self.clone_for_server();
+        let server = actix_web::test::start(move || {
+            App::new().service(web::scope("/api/v1").service(scope_factory(&cfg)))
+        });
+
+        let url = server.url("/api/v1").trim_end_matches('/').to_owned();
+        (Client::new(url), server)
+    }
+
+    fn clone_for_server(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            env_options: self.env_options.clone(),
+        }
+    }
+}
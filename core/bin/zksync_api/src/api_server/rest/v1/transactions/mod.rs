@@ -0,0 +1,62 @@
+//! Transaction status endpoints, scoped under `/api/v1/transactions`.
+//!
+//! Kept deliberately small: the one handler here exists to give [`super::client::PendingTx`]
+//! a way to poll a transaction's status by hash alone, without requiring the caller's account.
+
+// Built-in uses
+
+// External uses
+use actix_web::{
+    web::{self, Json},
+    Scope,
+};
+
+// Workspace uses
+use zksync_storage::ConnectionPool;
+use zksync_types::tx::TxHash;
+
+// Local uses
+use crate::api_server::rest::v1::{
+    accounts::types::AccountTxReceipt, client::TxReceipt, current_block, error::ApiError, JsonResult,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone)]
+struct ApiTransactionsData {
+    pool: ConnectionPool,
+}
+
+fn parse_tx_hash(raw: &str) -> Result<TxHash, ApiError> {
+    raw.parse()
+        .map_err(|_| ApiError::bad_request("tx_hash must be a transaction hash"))
+}
+
+async fn tx_receipt(
+    data: web::Data<ApiTransactionsData>,
+    tx_hash: web::Path<String>,
+) -> JsonResult<Option<TxReceipt>> {
+    let tx_hash = parse_tx_hash(&tx_hash)?;
+    let mut storage = data.pool.access_storage().await.map_err(ApiError::internal)?;
+
+    let response = storage
+        .chain()
+        .operations_ext_schema()
+        .get_tx_receipt(tx_hash)
+        .await
+        .map_err(ApiError::internal)?;
+    let current_block = current_block(&mut storage).await?;
+
+    Ok(Json(
+        response.map(|inner| AccountTxReceipt::from((inner, current_block)).receipt),
+    ))
+}
+
+pub fn api_scope(pool: ConnectionPool) -> Scope {
+    let data = ApiTransactionsData { pool };
+
+    web::scope("transactions")
+        .data(data)
+        .route("{tx_hash}/receipt", web::get().to(tx_receipt))
+}
@@ -0,0 +1,77 @@
+// Built-in uses
+use std::time::Duration;
+
+// External uses
+use zksync_types::{tx::TxHash, ExecutedOperations};
+
+// Local uses
+use crate::api_server::rest::v1::{
+    client::{ClientError, TxReceipt, WaitFor},
+    test_utils::TestServerConfig,
+};
+
+use super::api_scope;
+
+async fn first_tx_hash(cfg: &TestServerConfig, block: u32) -> anyhow::Result<TxHash> {
+    let mut storage = cfg.pool.access_storage().await?;
+    let transactions = storage.chain().block_schema().get_block_transactions(block).await?;
+
+    for op in transactions {
+        if let ExecutedOperations::Tx(tx) = op {
+            return Ok(tx.signed_tx.hash());
+        }
+    }
+    anyhow::bail!("block {} has no transactions in the test fixtures", block)
+}
+
+#[actix_rt::test]
+async fn transactions_scope() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+    let tx_hash = first_tx_hash(&cfg, 1).await?;
+
+    let (client, server) = cfg.start_server(move |cfg| api_scope(cfg.pool.clone()));
+
+    // A known, already-verified transaction.
+    assert!(matches!(
+        client.tx_receipt(tx_hash).await?,
+        Some(TxReceipt::Verified {
+            block: 1,
+            confirmations: 0,
+            ..
+        })
+    ));
+
+    // An unknown hash doesn't have a receipt.
+    assert_eq!(client.tx_receipt(TxHash::default()).await?, None);
+
+    // wait_for_receipt resolves immediately once the target state is already reached.
+    let receipt = client.wait_for_receipt(tx_hash, WaitFor::Verify).wait().await?;
+    assert!(matches!(receipt, TxReceipt::Verified { block: 1, .. }));
+
+    // wait_for_receipt times out if the transaction is never observed.
+    let timed_out = client
+        .wait_for_receipt(TxHash::default(), WaitFor::Commit)
+        .poll_interval(Duration::from_millis(10))
+        .timeout(Duration::from_millis(50))
+        .wait()
+        .await;
+    assert!(matches!(timed_out, Err(ClientError::Timeout(WaitFor::Commit))));
+
+    server.stop().await;
+    Ok(())
+}
+
+#[actix_rt::test]
+async fn malformed_tx_hash_is_a_bad_request() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+    let (_client, server) = cfg.start_server(move |cfg| api_scope(cfg.pool.clone()));
+
+    let url = format!("{}/transactions/not-a-hash/receipt", server.url("/api/v1"));
+    let response = reqwest::get(&url).await?;
+    assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+
+    server.stop().await;
+    Ok(())
+}
@@ -0,0 +1,39 @@
+//! Loopback HTTP client used by the public API server to reach the private API exposed by
+//! `zksync_core` (e.g. to read unconfirmed L1 deposits it is watching for).
+
+// Built-in uses
+
+// External uses
+use serde_json::Value;
+
+// Workspace uses
+use zksync_types::Address;
+
+/// Client for the private API exposed by the `zksync_core` mempool/eth-watcher.
+#[derive(Debug, Clone)]
+pub struct CoreApiClient {
+    inner: reqwest::Client,
+    url: String,
+}
+
+impl CoreApiClient {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            inner: reqwest::Client::new(),
+            url: url.into(),
+        }
+    }
+
+    /// Returns the raw `(eth_block, priority_op)` pairs for deposits that have been
+    /// observed on L1 for `address` but haven't accumulated enough confirmations to be
+    /// accepted into a block yet.
+    pub async fn get_unconfirmed_deposits(&self, address: Address) -> anyhow::Result<Value> {
+        let response = self
+            .inner
+            .get(&format!("{}/unconfirmed_deposits/{:#x}", self.url, address))
+            .send()
+            .await?;
+
+        Ok(response.json().await?)
+    }
+}
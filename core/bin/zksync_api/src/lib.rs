@@ -0,0 +1,8 @@
+//! The zkSync operator node API.
+//!
+//! This crate serves the public REST/JSON-RPC APIs backed by the node's storage, as well as
+//! the loopback client used to talk to the `zksync_core` private API.
+
+pub mod api_server;
+pub mod core_api_client;
+pub mod utils;
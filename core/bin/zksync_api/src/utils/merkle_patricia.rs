@@ -0,0 +1,271 @@
+//! A minimal Merkle-Patricia trie, built once from a fully-known key/value set and used to
+//! hand out inclusion proofs (e.g. "this receipt is in this block") without requiring the
+//! caller to trust the API server.
+//!
+//! This intentionally isn't a general-purpose, mutable trie (no incremental insert/delete):
+//! every user builds it once from every key it will ever hold via [`MerklePatriciaTree::build`].
+//! Unlike the canonical Ethereum state trie, child references are always the Keccak256 hash
+//! of the child's RLP encoding (no inlining of small nodes) -- simpler to reason about, and
+//! the proof sizes involved here (one trie per block) are tiny regardless.
+
+// Built-in uses
+
+// External uses
+use rlp::RlpStream;
+use tiny_keccak::{Hasher, Keccak};
+use zksync_types::H256;
+
+fn keccak256(bytes: &[u8]) -> H256 {
+    let mut hasher = Keccak::v256();
+    let mut output = [0u8; 32];
+    hasher.update(bytes);
+    hasher.finalize(&mut output);
+    H256::from(output)
+}
+
+fn bytes_to_nibbles(bytes: &[u8]) -> Vec<u8> {
+    let mut nibbles = Vec::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        nibbles.push(byte >> 4);
+        nibbles.push(byte & 0x0f);
+    }
+    nibbles
+}
+
+/// Ethereum's "hex-prefix" encoding: packs a nibble path plus a leaf/extension flag back
+/// into bytes, so a compact path can be told apart from a terminator byte in the RLP list.
+fn hex_prefix_encode(nibbles: &[u8], is_leaf: bool) -> Vec<u8> {
+    let odd = nibbles.len() % 2 == 1;
+    let mut prefixed = Vec::with_capacity(nibbles.len() / 2 + 1);
+    let flag = (if is_leaf { 2 } else { 0 }) + (if odd { 1 } else { 0 });
+
+    if odd {
+        prefixed.push(flag);
+        prefixed.extend_from_slice(nibbles);
+    } else {
+        prefixed.push(flag);
+        prefixed.push(0);
+        prefixed.extend_from_slice(nibbles);
+    }
+
+    // Re-pack the (possibly flag-prefixed) nibble stream into bytes.
+    let mut out = Vec::with_capacity(prefixed.len() / 2);
+    for pair in prefixed.chunks(2) {
+        out.push((pair[0] << 4) | pair[1]);
+    }
+    out
+}
+
+enum Node {
+    Leaf { path: Vec<u8>, value: Vec<u8> },
+    Extension { path: Vec<u8>, child: Box<Node> },
+    Branch { children: [Option<Box<Node>>; 16], value: Option<Vec<u8>> },
+}
+
+impl Node {
+    fn encode(&self) -> Vec<u8> {
+        let mut stream = RlpStream::new();
+        match self {
+            Node::Leaf { path, value } => {
+                stream.begin_list(2);
+                stream.append(&hex_prefix_encode(path, true));
+                stream.append(value);
+            }
+            Node::Extension { path, child } => {
+                stream.begin_list(2);
+                stream.append(&hex_prefix_encode(path, false));
+                stream.append(&keccak256(&child.encode()).as_bytes());
+            }
+            Node::Branch { children, value } => {
+                stream.begin_list(17);
+                for child in children {
+                    match child {
+                        Some(child) => stream.append(&keccak256(&child.encode()).as_bytes()),
+                        None => stream.append_empty_data(),
+                    };
+                }
+                match value {
+                    Some(value) => stream.append(value),
+                    None => stream.append_empty_data(),
+                };
+            }
+        }
+        stream.out().to_vec()
+    }
+}
+
+/// An inclusion proof for a single key: the ordered list of RLP-encoded nodes from the root
+/// down to the leaf holding `key`/`value`, plus the hash that must match the trusted root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof {
+    pub root: H256,
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+    pub nodes: Vec<Vec<u8>>,
+}
+
+/// A trie built once from a complete key/value set.
+pub struct MerklePatriciaTree {
+    root: Option<Node>,
+}
+
+impl MerklePatriciaTree {
+    /// Keccak256 hash of the RLP encoding of the empty string, i.e. the canonical "empty
+    /// trie" root used by an empty block.
+    pub fn empty_root() -> H256 {
+        keccak256(&rlp::encode(&""))
+    }
+
+    /// Builds a trie from every `(key, value)` pair it will ever hold. `keys` must be unique.
+    pub fn build(mut entries: Vec<(Vec<u8>, Vec<u8>)>) -> Self {
+        if entries.is_empty() {
+            return Self { root: None };
+        }
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let pairs: Vec<(Vec<u8>, Vec<u8>)> = entries
+            .into_iter()
+            .map(|(key, value)| (bytes_to_nibbles(&key), value))
+            .collect();
+
+        Self {
+            root: Some(build_node(&pairs)),
+        }
+    }
+
+    pub fn root_hash(&self) -> H256 {
+        match &self.root {
+            Some(node) => keccak256(&node.encode()),
+            None => Self::empty_root(),
+        }
+    }
+
+    /// Returns the inclusion proof for `key`, or `None` if the tree is empty or doesn't hold
+    /// that key. The returned `nodes` are ordered root-first.
+    pub fn proof(&self, key: &[u8]) -> Option<MerkleProof> {
+        let root = self.root.as_ref()?;
+        let nibbles = bytes_to_nibbles(key);
+        let mut nodes = Vec::new();
+        let value = walk(root, &nibbles, &mut nodes)?;
+
+        Some(MerkleProof {
+            root: self.root_hash(),
+            key: key.to_vec(),
+            value,
+            nodes,
+        })
+    }
+}
+
+fn walk(node: &Node, nibbles: &[u8], nodes: &mut Vec<Vec<u8>>) -> Option<Vec<u8>> {
+    nodes.push(node.encode());
+    match node {
+        Node::Leaf { path, value } => (path == nibbles).then(|| value.clone()),
+        Node::Extension { path, child } => {
+            let rest = nibbles.strip_prefix(path.as_slice())?;
+            walk(child, rest, nodes)
+        }
+        Node::Branch { children, value } => {
+            if nibbles.is_empty() {
+                return value.clone();
+            }
+            let child = children[nibbles[0] as usize].as_ref()?;
+            walk(child, &nibbles[1..], nodes)
+        }
+    }
+}
+
+fn common_prefix_len(pairs: &[(Vec<u8>, Vec<u8>)]) -> usize {
+    let first = &pairs[0].0;
+    let mut len = first.len();
+    for (key, _) in &pairs[1..] {
+        len = len.min(key.len());
+        len = (0..len).take_while(|&i| key[i] == first[i]).count().min(len);
+    }
+    len
+}
+
+fn build_node(pairs: &[(Vec<u8>, Vec<u8>)]) -> Node {
+    if pairs.len() == 1 {
+        let (path, value) = &pairs[0];
+        return Node::Leaf {
+            path: path.clone(),
+            value: value.clone(),
+        };
+    }
+
+    let prefix_len = common_prefix_len(pairs);
+    if prefix_len > 0 {
+        let stripped: Vec<_> = pairs
+            .iter()
+            .map(|(key, value)| (key[prefix_len..].to_vec(), value.clone()))
+            .collect();
+        return Node::Extension {
+            path: pairs[0].0[..prefix_len].to_vec(),
+            child: Box::new(build_branch(&stripped)),
+        };
+    }
+
+    build_branch(pairs)
+}
+
+fn build_branch(pairs: &[(Vec<u8>, Vec<u8>)]) -> Node {
+    let mut children: [Option<Box<Node>>; 16] = Default::default();
+    let mut value = None;
+
+    for nibble in 0..16u8 {
+        let group: Vec<_> = pairs
+            .iter()
+            .filter(|(key, _)| key.first() == Some(&nibble))
+            .map(|(key, value)| (key[1..].to_vec(), value.clone()))
+            .collect();
+        if !group.is_empty() {
+            children[nibble as usize] = Some(Box::new(build_node(&group)));
+        }
+    }
+
+    if let Some((_, terminal_value)) = pairs.iter().find(|(key, _)| key.is_empty()) {
+        value = Some(terminal_value.clone());
+    }
+
+    Node::Branch { children, value }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_tree_has_no_proof() {
+        let tree = MerklePatriciaTree::build(vec![]);
+        assert_eq!(tree.root_hash(), MerklePatriciaTree::empty_root());
+        assert!(tree.proof(&[0x01]).is_none());
+    }
+
+    #[test]
+    fn single_leaf_proof_round_trips() {
+        let key = rlp::encode(&0u32).to_vec();
+        let value = b"only receipt".to_vec();
+        let tree = MerklePatriciaTree::build(vec![(key.clone(), value.clone())]);
+
+        let proof = tree.proof(&key).unwrap();
+        assert_eq!(proof.value, value);
+        assert_eq!(proof.nodes.len(), 1);
+        assert_eq!(proof.root, tree.root_hash());
+    }
+
+    #[test]
+    fn proof_covers_every_inserted_key() {
+        let entries: Vec<_> = (0..16u32)
+            .map(|i| (rlp::encode(&i).to_vec(), format!("receipt-{}", i).into_bytes()))
+            .collect();
+        let tree = MerklePatriciaTree::build(entries.clone());
+
+        for (key, value) in entries {
+            let proof = tree.proof(&key).unwrap();
+            assert_eq!(proof.value, value);
+            assert_eq!(proof.root, tree.root_hash());
+            assert!(proof.nodes.len() >= 1);
+        }
+    }
+}
@@ -0,0 +1,4 @@
+//! Small helpers shared across the API server implementations.
+
+pub mod merkle_patricia;
+pub mod token_db_cache;
@@ -0,0 +1,50 @@
+//! An in-memory cache for token metadata backed by storage on a miss.
+
+// Built-in uses
+use std::{collections::HashMap, sync::Arc};
+
+// External uses
+use tokio::sync::RwLock;
+
+// Workspace uses
+use zksync_storage::ConnectionPool;
+use zksync_types::{Token, TokenId};
+
+/// Caches `Token` metadata so repeated lookups (e.g. while formatting a page of receipts)
+/// don't each round-trip to storage; tokens never change once added, so entries are cached
+/// for the lifetime of the process.
+#[derive(Clone)]
+pub struct TokenDBCache {
+    pool: ConnectionPool,
+    cache: Arc<RwLock<HashMap<TokenId, Token>>>,
+}
+
+impl TokenDBCache {
+    pub fn new(pool: ConnectionPool) -> Self {
+        Self {
+            pool,
+            cache: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Exposes the underlying connection pool so callers that already depend on a
+    /// `TokenDBCache` don't need to thread a second handle through just to reach storage.
+    pub(crate) fn pool(&self) -> ConnectionPool {
+        self.pool.clone()
+    }
+
+    pub async fn get_token(&self, token_id: TokenId) -> anyhow::Result<Option<Token>> {
+        if let Some(token) = self.cache.read().await.get(&token_id).cloned() {
+            return Ok(Some(token));
+        }
+
+        let mut storage = self.pool.access_storage().await?;
+        let token = storage.tokens_schema().get_token(token_id.into()).await?;
+
+        if let Some(token) = &token {
+            self.cache.write().await.insert(token_id, token.clone());
+        }
+
+        Ok(token)
+    }
+}
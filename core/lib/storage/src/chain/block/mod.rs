@@ -0,0 +1,78 @@
+//! Block-level schema.
+//!
+//! Only the queries needed by the `/api/v1` block endpoints are shown here; `get_block_transactions`,
+//! `save_block_transactions` and `get_block_number_by_commit_tx_hash` already exist elsewhere on
+//! this schema and are unchanged.
+//!
+//! `commit_eth_block`/`verify_eth_block` (below, and on the `AccountTxReceiptResponse`/
+//! `AccountOpReceiptResponse` rows in [`super::operations_ext::records`]) are new columns
+//! recording the L1 block each commit/verify transaction was mined in, populated by the same
+//! code path that already records `commit_tx_hash`/`verify_tx_hash` when an Ethereum watcher
+//! observes the transaction confirmed.
+
+// Built-in uses
+
+// External uses
+
+// Workspace uses
+use zksync_types::BlockNumber;
+
+// Local uses
+use crate::{QueryResult, StorageProcessor};
+
+/// Block-related queries.
+pub struct BlockSchema<'a, 'c>(pub &'a mut StorageProcessor<'c>);
+
+impl<'a, 'c> BlockSchema<'a, 'c> {
+    /// Whether `block` has actually been produced, as opposed to merely being a number beyond
+    /// the chain's current height. Needed because an empty, already-produced block and a block
+    /// that hasn't happened yet both have zero transactions.
+    pub async fn block_exists(&mut self, block: BlockNumber) -> QueryResult<bool> {
+        let record = sqlx::query!(
+            r#"SELECT number FROM blocks WHERE number = $1"#,
+            i64::from(block)
+        )
+        .fetch_optional(self.0.conn())
+        .await?;
+
+        Ok(record.is_some())
+    }
+
+    /// The commit/verify transaction hashes and the L1 blocks they were mined in, or all-`None`
+    /// if `block` hasn't been committed yet.
+    pub async fn get_block_commit_verify_info(
+        &mut self,
+        block: BlockNumber,
+    ) -> QueryResult<(Option<Vec<u8>>, Option<i64>, Option<Vec<u8>>, Option<i64>)> {
+        let record = sqlx::query!(
+            r#"
+            SELECT commit_tx_hash, commit_eth_block, verify_tx_hash, verify_eth_block
+            FROM eth_operations
+            WHERE block_number = $1
+            "#,
+            i64::from(block)
+        )
+        .fetch_optional(self.0.conn())
+        .await?;
+
+        Ok(record
+            .map(|r| (r.commit_tx_hash, r.commit_eth_block, r.verify_tx_hash, r.verify_eth_block))
+            .unwrap_or((None, None, None, None)))
+    }
+
+    /// The most recent block this node has committed to the L1 contract; the reference point
+    /// `confirmations` on a `Committed`/`Verified` receipt is measured against.
+    pub async fn get_last_committed_block(&mut self) -> QueryResult<BlockNumber> {
+        let record = sqlx::query!(
+            r#"
+            SELECT COALESCE(MAX(block_number), 0) AS "block_number!"
+            FROM eth_operations
+            WHERE commit_tx_hash IS NOT NULL
+            "#
+        )
+        .fetch_one(self.0.conn())
+        .await?;
+
+        Ok(record.block_number as BlockNumber)
+    }
+}
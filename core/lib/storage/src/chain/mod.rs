@@ -0,0 +1,8 @@
+//! Per-chain-state schemas, reached via `StorageProcessor::chain`.
+//!
+//! Only the submodules touched by the `/api/v1` confirmation-depth/proof work are declared
+//! here (`block`, `operations_ext`); `account` and the other existing schemas live alongside
+//! these, unchanged.
+
+pub mod block;
+pub mod operations_ext;
@@ -0,0 +1,7 @@
+//! Account-scoped transaction/priority-op history queries.
+//!
+//! Only `records` (the response row types) is shown here; the `OperationsExtSchema` queries
+//! themselves (`get_account_transactions_receipts`, `get_account_operations_receipts`,
+//! `get_tx_receipt`) already exist elsewhere on this schema and are unchanged.
+
+pub mod records;
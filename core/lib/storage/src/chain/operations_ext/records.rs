@@ -0,0 +1,40 @@
+//! Row types returned by `OperationsExtSchema`'s account-receipt queries.
+
+// Built-in uses
+
+// External uses
+use serde::{Deserialize, Serialize};
+
+// Workspace uses
+
+// Local uses
+
+/// A single transaction receipt, as seen from one account's history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountTxReceiptResponse {
+    pub block_number: i64,
+    pub block_index: Option<u32>,
+    pub tx_hash: Vec<u8>,
+    pub success: bool,
+    pub fail_reason: Option<String>,
+    pub commit_tx_hash: Option<Vec<u8>>,
+    /// L1 block the commit transaction was mined in, once observed on Ethereum.
+    pub commit_eth_block: Option<i64>,
+    pub verify_tx_hash: Option<Vec<u8>>,
+    /// L1 block the verify transaction was mined in, once observed on Ethereum.
+    pub verify_eth_block: Option<i64>,
+}
+
+/// A single priority operation receipt, as seen from one account's history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountOpReceiptResponse {
+    pub block_number: i64,
+    pub block_index: u32,
+    pub eth_hash: Vec<u8>,
+    pub commit_tx_hash: Option<Vec<u8>>,
+    /// L1 block the commit transaction was mined in, once observed on Ethereum.
+    pub commit_eth_block: Option<i64>,
+    pub verify_tx_hash: Option<Vec<u8>>,
+    /// L1 block the verify transaction was mined in, once observed on Ethereum.
+    pub verify_eth_block: Option<i64>,
+}